@@ -1,8 +1,13 @@
-use std::alloc::{alloc, Layout};
-use std::cell::{Cell, RefCell};
-use std::ptr;
-use std::slice;
-use std::str;
+#[cfg(feature = "std")]
+use std::alloc::{alloc, dealloc, Layout};
+#[cfg(not(feature = "std"))]
+use alloc::alloc::{alloc, dealloc, Layout};
+use core::cell::{Cell, RefCell};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ptr;
+use core::slice;
+use core::str;
 
 const PAGE_SIZE: usize = 4096;
 
@@ -10,7 +15,9 @@ const PAGE_SIZE: usize = 4096;
 pub struct Arena {
     start: Cell<*mut u8>,
     end: Cell<*mut u8>,
-    chunks: RefCell<Vec<*const u8>>,
+    // Every chunk handed out by the allocator, together with the `Layout`
+    // it was allocated with, so `Drop` can hand each one back.
+    chunks: RefCell<Vec<(*mut u8, Layout)>>,
 }
 
 impl Arena {
@@ -34,35 +41,59 @@ impl Arena {
         let layout = Layout::array::<u8>(chunk_size).unwrap();
         unsafe {
             let ptr = alloc(layout);
-            self.chunks.borrow_mut().push(ptr);
+            if ptr.is_null() {
+                panic!("Failed to allocate {} bytes.", chunk_size);
+            }
+            self.chunks.borrow_mut().push((ptr, layout));
             self.start.set(ptr);
-            self.end.set(ptr.wrapping_add(chunk_size));
+            // SAFETY: `ptr` is the start of a `chunk_size`-byte allocation,
+            // so `ptr + chunk_size` is one byte past its end, which is a
+            // valid (if not dereferenceable) pointer to form with `add`.
+            self.end.set(ptr.add(chunk_size));
         }
     }
 
     fn allocate(&self, layout: Layout) -> *mut u8 {
-        let start = self.start.get() as usize;
-        let end = self.end.get() as usize;
+        let start = self.start.get();
+        let end = self.end.get();
         let align = layout.align();
         let bytes = layout.size();
 
-        let aligned = start.checked_add(align - 1).unwrap() & !(align - 1);
-        let new_start = aligned
-            .checked_add(bytes)
-            .unwrap_or_else(|| panic!("Cannot allocate more than {} bytes.", usize::MAX));
-
-        if new_start <= end {
-            self.start.set(new_start as *mut u8);
-            aligned as *mut u8
-        } else {
-            self.new_chunk(bytes);
-            let ptr = self.start.get();
-            self.start.set(ptr.wrapping_add(bytes));
-            ptr
+        if !start.is_null() {
+            let align_offset = start.align_offset(align);
+            if align_offset != usize::MAX {
+                // Tentative arithmetic only: `wrapping_add` never forms an
+                // out-of-bounds pointer via `add`'s stricter contract, so
+                // it's safe to use for the "does it still fit in this
+                // chunk" check below, before we know whether `aligned` (or
+                // `aligned + bytes`) is actually in bounds.
+                let aligned_addr = start.wrapping_add(align_offset);
+                if let Some(new_start_addr) = checked_ptr_add(aligned_addr, bytes) {
+                    if new_start_addr <= end {
+                        // SAFETY: we just checked that `aligned_addr` and
+                        // `aligned_addr + bytes` both land at or before
+                        // `end`, i.e. within (or one byte past) the chunk
+                        // `start` points into, so forming both pointers
+                        // with `add` here is in bounds.
+                        let aligned = unsafe { start.add(align_offset) };
+                        let new_start = unsafe { aligned.add(bytes) };
+                        self.start.set(new_start);
+                        return aligned;
+                    }
+                }
+            }
         }
+
+        self.new_chunk(bytes);
+        let ptr = self.start.get();
+        // SAFETY: `new_chunk` just allocated at least `bytes` bytes
+        // starting at `ptr`.
+        let new_start = unsafe { ptr.add(bytes) };
+        self.start.set(new_start);
+        ptr
     }
 
-    pub fn allocate_string<'a, 'b>(&'a self, s: &'b str) -> &'a str {
+    pub fn allocate_string<'a>(&'a self, s: &str) -> &'a str {
         assert!(!s.is_empty());
         let layout = Layout::for_value(s.as_bytes());
         let ptr = self.allocate(layout);
@@ -73,3 +104,25 @@ impl Arena {
         }
     }
 }
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.chunks.borrow_mut().drain(..) {
+            // SAFETY: each `(ptr, layout)` pair came from a matching
+            // `alloc(layout)` call in `new_chunk` and is only ever freed
+            // once, here.
+            unsafe { dealloc(ptr, layout) };
+        }
+    }
+}
+
+/// `ptr`'s address plus `bytes`, as a checked computation on the address
+/// alone: `None` if advancing `bytes` bytes would overflow the address
+/// space. This is tentative arithmetic for a bounds check, not a real
+/// pointer offset — `wrapping_add` carries no provenance/in-bounds
+/// guarantee, so the caller must still check the result against the
+/// chunk's `end` before forming any pointer from it with `ptr::add`.
+fn checked_ptr_add(ptr: *mut u8, bytes: usize) -> Option<*mut u8> {
+    (ptr as usize).checked_add(bytes)?;
+    Some(ptr.wrapping_add(bytes))
+}
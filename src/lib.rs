@@ -2,7 +2,7 @@
 //!
 //! # Examples
 //!
-//! ```
+//! ```ignore
 //! use string_interner::Symbol;
 //!
 //! let s = Symbol::intern("Hello, world!");
@@ -11,17 +11,77 @@
 //! assert_eq!(s2.as_str(), "Hello, world!");
 //! assert_eq!(s, s2);
 //! ```
+//!
+//! (`ignore`d above since this example needs the default `std` feature;
+//! see [`Symbol`] for the same example run as a doctest.)
+//!
+//! Without the default `std` feature the crate builds under `#![no_std]`
+//! (with `extern crate alloc`) and only exposes [`StringInterner`] /
+//! [`LocalSymbol`]: the process-global [`Symbol`] API needs `std` for its
+//! thread-safe storage.
+//!
+//! With the `serde` feature enabled, a [`StringInterner`] can be
+//! serialized and deserialized as a snapshot of its string table; see its
+//! `Serialize`/`Deserialize` impls.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 mod arena;
 
 use arena::Arena;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::fmt;
-use std::mem;
+#[cfg(all(feature = "serde", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::fmt;
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use core::hash::{Hash, Hasher};
+use core::mem;
+
+// `hashbrown`'s raw-entry API lets `StringInterner::get_or_intern` hash a
+// candidate string once and reuse that hash for both the lookup and the
+// insert, instead of hashing it again inside `HashMap::insert` on a miss.
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+
+/// Log2 of the number of shards the global interner is split into (so
+/// `NUM_SHARDS` is `1 << SHARD_BITS`). Picking the shard by
+/// the string's hash keeps lock contention down when many threads intern
+/// concurrently.
+#[cfg(feature = "std")]
+const SHARD_BITS: u32 = 4;
+#[cfg(feature = "std")]
+const NUM_SHARDS: usize = 1 << SHARD_BITS;
+#[cfg(feature = "std")]
+const SHARD_MASK: u32 = (NUM_SHARDS as u32) - 1;
 
+/// A globally unique, process-wide handle to an interned string.
+///
+/// Unlike a thread-local interner, a `Symbol` is valid no matter which
+/// thread produced it: `as_str` always resolves it against the same
+/// process-global table.
+///
+/// Only available with the `std` feature, since the global table it
+/// resolves against needs `std`'s synchronization primitives.
+///
+/// ```
+/// use string_interner::Symbol;
+///
+/// let s = Symbol::intern("Hello, world!");
+/// assert_eq!(s.as_str(), "Hello, world!");
+/// let s2 = Symbol::intern("Hello, world!");
+/// assert_eq!(s2.as_str(), "Hello, world!");
+/// assert_eq!(s, s2);
+/// ```
+#[cfg(feature = "std")]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Symbol(u32);
 
+#[cfg(feature = "std")]
 impl Symbol {
     /// Get the internal `u32` representation.
     pub fn as_u32(self) -> u32 {
@@ -30,42 +90,74 @@ impl Symbol {
 
     /// Intern a [`String`] and receive a Symbol that points to it.
     pub fn intern<S: AsRef<str>>(s: S) -> Self {
-        with_interner(move |interner| interner.intern(s))
+        global_interner().intern(s.as_ref())
     }
 
     /// Get the string representation that this token points to.
     pub fn as_str(self) -> &'static str {
-        with_interner(|interner| interner.symbol_to_str(self))
+        global_interner().symbol_to_str(self)
     }
 }
 
+#[cfg(feature = "std")]
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+#[cfg(feature = "std")]
 impl From<String> for Symbol {
     fn from(s: String) -> Self {
         Symbol::intern(s)
     }
 }
 
+#[cfg(feature = "std")]
 impl From<&str> for Symbol {
     fn from(s: &str) -> Self {
         Symbol::intern(s)
     }
 }
 
+/// A handle to a string interned in a particular [`StringInterner`].
+///
+/// Unlike [`Symbol`], a `LocalSymbol` only makes sense alongside the
+/// `StringInterner` instance that produced it; resolving it against a
+/// different instance will return the wrong string or `None`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LocalSymbol(u32);
+
+impl LocalSymbol {
+    /// Get the internal `u32` representation.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+/// An explicit, standalone interning table.
+///
+/// Where [`Symbol`] is backed by a single process-wide table, a
+/// `StringInterner` is an independent namespace: create one per
+/// compilation unit, request, or test and drop it to reclaim every string
+/// it holds. Symbols returned by [`get_or_intern`](Self::get_or_intern)
+/// are only valid for the instance that produced them.
 #[derive(Debug)]
-struct Interner {
+pub struct StringInterner {
     map: HashMap<&'static str, u32>,
     strings: Vec<&'static str>,
     arena: Arena,
 }
 
-impl Interner {
-    fn new() -> Self {
+// SAFETY: a `StringInterner` is either owned by a single thread, or, as the
+// shard of a `GlobalInterner`, only ever reachable through the `Mutex` that
+// guards it. Either way at most one thread touches its `Arena` (and the raw
+// pointers inside it) at a time.
+unsafe impl Send for StringInterner {}
+
+impl StringInterner {
+    /// Create a new, empty interner.
+    pub fn new() -> Self {
         Self {
             map: HashMap::new(),
             strings: Vec::new(),
@@ -73,85 +165,532 @@ impl Interner {
         }
     }
 
-    fn intern<S: AsRef<str>>(&mut self, s: S) -> Symbol {
-        if let Some(idx) = self.map.get(s.as_ref()) {
-            return Symbol(*idx);
+    /// Intern `s`, returning the existing symbol if it was already
+    /// interned.
+    pub fn get_or_intern(&mut self, s: &str) -> LocalSymbol {
+        self.get_or_intern_below(s, u32::MAX)
+    }
+
+    /// Like [`get_or_intern`](Self::get_or_intern), but panics in debug
+    /// builds if assigning a new index would exceed `max_idx`.
+    ///
+    /// The global interner's shards use this to enforce the shard-local
+    /// capacity implied by packing a shard index into the low bits of
+    /// every `Symbol`, the same way `RcShard::intern` bounds its own
+    /// indices.
+    fn get_or_intern_below(&mut self, s: &str, max_idx: u32) -> LocalSymbol {
+        let hash_builder = self.map.hasher().clone();
+        let hash = hash_builder.hash_one(s);
+
+        match self.map.raw_entry_mut().from_hash(hash, |k| *k == s) {
+            RawEntryMut::Occupied(entry) => LocalSymbol(*entry.get()),
+            RawEntryMut::Vacant(entry) => {
+                let idx = self.strings.len();
+                debug_assert!(
+                    idx <= (max_idx as usize),
+                    "Cannot intern more than {} strings",
+                    max_idx
+                );
+                let idx = idx as u32;
+                let allocated_str: &'static str =
+                    unsafe { mem::transmute(self.arena.allocate_string(s)) };
+                self.strings.push(allocated_str);
+                entry.insert_with_hasher(hash, allocated_str, idx, |k| hash_builder.hash_one(k));
+                LocalSymbol(idx)
+            }
         }
+    }
+
+    /// Resolve a symbol back to its string, or `None` if it wasn't
+    /// produced by this interner.
+    pub fn resolve(&self, symbol: LocalSymbol) -> Option<&str> {
+        self.strings.get(symbol.as_u32() as usize).copied()
+    }
+
+    /// The number of strings currently interned.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
 
-        let idx = self.strings.len();
-        debug_assert!(
-            idx <= (u32::MAX as usize),
-            "Cannot intern more than {} strings",
-            u32::MAX
-        );
-        let idx = idx as u32;
-        let allocated_str: &'static str =
-            unsafe { mem::transmute(self.arena.allocate_string(s.as_ref())) };
-        self.strings.push(allocated_str);
-        self.map.insert(allocated_str, idx);
-        Symbol(idx)
+    /// Whether no strings have been interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+impl Default for StringInterner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serializes as the ordered table of interned strings (`self.strings`),
+/// so a `LocalSymbol`'s `u32` is just its index into the resulting
+/// sequence.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringInterner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.strings.serialize(serializer)
+    }
+}
+
+/// Deserializes an ordered table of strings and re-interns each one, in
+/// order, into a fresh [`StringInterner`] and arena, so every `u32` id
+/// round-trips to the same string it named before serialization.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StringInterner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        let mut interner = StringInterner::new();
+        for s in strings {
+            interner.get_or_intern(&s);
+        }
+        Ok(interner)
+    }
+}
+
+/// Process-global, sharded interner backing [`Symbol`].
+///
+/// Each shard is an independent [`StringInterner`]; strings are routed to
+/// a shard by hash so that interning from different threads rarely
+/// contends on the same lock. A `Symbol`'s `u32` is the shard index packed
+/// into its low bits and the shard-local index in the remaining bits.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct GlobalInterner {
+    shards: Vec<std::sync::Mutex<StringInterner>>,
+}
+
+#[cfg(feature = "std")]
+impl GlobalInterner {
+    fn new() -> Self {
+        Self {
+            shards: (0..NUM_SHARDS)
+                .map(|_| std::sync::Mutex::new(StringInterner::new()))
+                .collect(),
+        }
+    }
+
+    fn intern(&self, s: &str) -> Symbol {
+        let shard_idx = shard_index(s);
+        let mut shard = self.shards[shard_idx as usize].lock().unwrap();
+        let local = shard.get_or_intern_below(s, u32::MAX >> SHARD_BITS);
+        Symbol((local.as_u32() << SHARD_BITS) | shard_idx)
     }
 
     fn symbol_to_str(&self, symbol: Symbol) -> &'static str {
-        self.strings[symbol.as_u32() as usize]
+        let shard_idx = symbol.as_u32() & SHARD_MASK;
+        let local_idx = (symbol.as_u32() >> SHARD_BITS) as usize;
+        let shard = self.shards[shard_idx as usize].lock().unwrap();
+        shard.strings[local_idx]
     }
 }
 
-fn with_interner<F, T>(f: F) -> T
-where
-    F: FnOnce(&mut Interner) -> T,
-{
-    INTERNER.with(|interner| f(&mut interner.borrow_mut()))
+#[cfg(feature = "std")]
+fn global_interner() -> &'static GlobalInterner {
+    static INTERNER: std::sync::OnceLock<GlobalInterner> = std::sync::OnceLock::new();
+    INTERNER.get_or_init(GlobalInterner::new)
+}
+
+/// Pick the shard a string's entry belongs to, by hash, so that
+/// interning the same string always lands on the same shard.
+#[cfg(feature = "std")]
+fn shard_index(s: &str) -> u32 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    s.hash(&mut hasher);
+    (hasher.finish() as u32) & SHARD_MASK
 }
 
-thread_local! {
-    static INTERNER: RefCell<Interner> = RefCell::new(Interner::new());
+/// A single interned string entry in an [`RcSymbol`] table: the string
+/// itself plus how many live `RcSymbol`s point to it.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct RcEntry {
+    string: &'static str,
+    count: usize,
+}
+
+/// One shard of the process-global [`RcSymbol`] table.
+///
+/// Reclaimed slots are tracked in `free` so ids stay dense and get reused
+/// rather than growing `entries` forever; the arena itself is never
+/// shrunk, since it can only free whole chunks, not individual strings.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct RcShard {
+    map: HashMap<&'static str, u32>,
+    entries: Vec<Option<RcEntry>>,
+    free: Vec<u32>,
+    arena: Arena,
+}
+
+// SAFETY: an `RcShard` is only ever reachable through the `Mutex` that
+// guards it, so at most one thread touches its `Arena` at a time.
+#[cfg(feature = "std")]
+unsafe impl Send for RcShard {}
+
+#[cfg(feature = "std")]
+impl RcShard {
+    fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            entries: Vec::new(),
+            free: Vec::new(),
+            arena: Arena::new(),
+        }
+    }
+
+    fn intern(&mut self, s: &str, shard_idx: u32) -> RcSymbol {
+        let hash_builder = self.map.hasher().clone();
+        let hash = hash_builder.hash_one(s);
+
+        match self.map.raw_entry_mut().from_hash(hash, |k| *k == s) {
+            RawEntryMut::Occupied(entry) => {
+                let local_idx = *entry.get();
+                self.entries[local_idx as usize]
+                    .as_mut()
+                    .expect("interned string has a live entry")
+                    .count += 1;
+                RcSymbol((local_idx << SHARD_BITS) | shard_idx)
+            }
+            RawEntryMut::Vacant(entry) => {
+                let allocated_str: &'static str =
+                    unsafe { mem::transmute(self.arena.allocate_string(s)) };
+                let local_idx = match self.free.pop() {
+                    Some(local_idx) => {
+                        self.entries[local_idx as usize] = Some(RcEntry {
+                            string: allocated_str,
+                            count: 1,
+                        });
+                        local_idx
+                    }
+                    None => {
+                        let local_idx = self.entries.len();
+                        debug_assert!(
+                            local_idx <= ((u32::MAX >> SHARD_BITS) as usize),
+                            "Cannot intern more than {} strings per shard",
+                            u32::MAX >> SHARD_BITS
+                        );
+                        self.entries.push(Some(RcEntry {
+                            string: allocated_str,
+                            count: 1,
+                        }));
+                        local_idx as u32
+                    }
+                };
+                entry.insert_with_hasher(hash, allocated_str, local_idx, |k| {
+                    hash_builder.hash_one(k)
+                });
+                RcSymbol((local_idx << SHARD_BITS) | shard_idx)
+            }
+        }
+    }
+
+    fn incref(&mut self, local_idx: u32) {
+        self.entries[local_idx as usize]
+            .as_mut()
+            .expect("interned string has a live entry")
+            .count += 1;
+    }
+
+    fn decref(&mut self, local_idx: u32) {
+        let entry = self.entries[local_idx as usize]
+            .as_mut()
+            .expect("interned string has a live entry");
+        entry.count -= 1;
+        if entry.count == 0 {
+            let string = entry.string;
+            self.entries[local_idx as usize] = None;
+            self.map.remove(string);
+            self.free.push(local_idx);
+        }
+    }
+
+    fn resolve(&self, local_idx: u32) -> &'static str {
+        self.entries[local_idx as usize]
+            .as_ref()
+            .expect("interned string has a live entry")
+            .string
+    }
+}
+
+/// Process-global, sharded, reference-counted interner backing
+/// [`RcSymbol`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct RcGlobalInterner {
+    shards: Vec<std::sync::Mutex<RcShard>>,
+}
+
+#[cfg(feature = "std")]
+impl RcGlobalInterner {
+    fn new() -> Self {
+        Self {
+            shards: (0..NUM_SHARDS)
+                .map(|_| std::sync::Mutex::new(RcShard::new()))
+                .collect(),
+        }
+    }
+
+    fn intern(&self, s: &str) -> RcSymbol {
+        let shard_idx = shard_index(s);
+        let mut shard = self.shards[shard_idx as usize].lock().unwrap();
+        shard.intern(s, shard_idx)
+    }
+
+    fn incref(&self, symbol: &RcSymbol) {
+        let shard_idx = symbol.as_u32() & SHARD_MASK;
+        let local_idx = symbol.as_u32() >> SHARD_BITS;
+        self.shards[shard_idx as usize]
+            .lock()
+            .unwrap()
+            .incref(local_idx);
+    }
+
+    fn decref(&self, symbol: &RcSymbol) {
+        let shard_idx = symbol.as_u32() & SHARD_MASK;
+        let local_idx = symbol.as_u32() >> SHARD_BITS;
+        self.shards[shard_idx as usize]
+            .lock()
+            .unwrap()
+            .decref(local_idx);
+    }
+
+    fn as_str(&self, symbol: &RcSymbol) -> &'static str {
+        let shard_idx = symbol.as_u32() & SHARD_MASK;
+        let local_idx = symbol.as_u32() >> SHARD_BITS;
+        self.shards[shard_idx as usize]
+            .lock()
+            .unwrap()
+            .resolve(local_idx)
+    }
+}
+
+#[cfg(feature = "std")]
+fn rc_global_interner() -> &'static RcGlobalInterner {
+    static INTERNER: std::sync::OnceLock<RcGlobalInterner> = std::sync::OnceLock::new();
+    INTERNER.get_or_init(RcGlobalInterner::new)
+}
+
+/// A reference-counted handle to an interned string.
+///
+/// Unlike [`Symbol`], which leaks every string it interns for the life of
+/// the process, an `RcSymbol`'s slot (and its entry in the lookup table)
+/// is reclaimed once the last clone of it is dropped, so interning the
+/// same string again reuses a fresh id. The backing arena bytes are only
+/// freed when the whole process exits.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RcSymbol(u32);
+
+#[cfg(feature = "std")]
+impl RcSymbol {
+    /// Get the internal `u32` representation.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Intern a [`String`], incrementing its refcount if it was already
+    /// interned.
+    pub fn intern<S: AsRef<str>>(s: S) -> Self {
+        rc_global_interner().intern(s.as_ref())
+    }
+
+    /// Get the string representation that this token points to.
+    pub fn as_str(&self) -> &'static str {
+        rc_global_interner().as_str(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for RcSymbol {
+    fn clone(&self) -> Self {
+        rc_global_interner().incref(self);
+        RcSymbol(self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for RcSymbol {
+    fn drop(&mut self) {
+        rc_global_interner().decref(self);
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for RcSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for RcSymbol {}
+
+#[cfg(feature = "std")]
+impl fmt::Display for RcSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
 
-    #[test]
-    fn test() {
-        let input1 = " ".repeat(4096);
-        let s1 = Symbol::intern(&input1);
-        assert_eq!(s1.as_str(), &input1);
-        let input2 = "+".repeat(1);
-        let s2 = Symbol::intern(&input2);
-        assert_eq!(s2.as_str(), &input2);
-        let input3 = "-".repeat(4097);
-        let s3 = Symbol::intern(&input3);
-        assert_eq!(s3.as_str(), &input3);
-
-        assert_eq!(s1.as_str(), &input1);
-        assert_eq!(s2.as_str(), &input2);
-        assert_eq!(s3.as_str(), &input3);
+    // `Symbol` and `RcSymbol` are only available with the `std` feature
+    // (see their `#[cfg(feature = "std")]` definitions above), so every
+    // test that touches them lives in this submodule instead of the
+    // top-level one, which must also compile and pass under
+    // `--no-default-features`.
+    #[cfg(feature = "std")]
+    mod global_symbol {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let input1 = " ".repeat(4096);
+            let s1 = Symbol::intern(&input1);
+            assert_eq!(s1.as_str(), &input1);
+            let input2 = "+".to_string();
+            let s2 = Symbol::intern(&input2);
+            assert_eq!(s2.as_str(), &input2);
+            let input3 = "-".repeat(4097);
+            let s3 = Symbol::intern(&input3);
+            assert_eq!(s3.as_str(), &input3);
+
+            assert_eq!(s1.as_str(), &input1);
+            assert_eq!(s2.as_str(), &input2);
+            assert_eq!(s3.as_str(), &input3);
+        }
+
+        #[test]
+        fn test_simple_interning() {
+            let s = Symbol::intern("Hello");
+            assert_eq!(s.as_str(), "Hello");
+        }
+
+        #[test]
+        fn test_interning_same_string_multiple_times() {
+            let s1 = Symbol::intern("Hello, world");
+            let s2 = Symbol::intern("Hello, world");
+            assert_eq!(s1.as_str(), "Hello, world");
+            assert_eq!(s1, s2);
+            assert_eq!(s2.as_str(), "Hello, world");
+        }
+
+        #[test]
+        fn test_interning_different_strings() {
+            let s1: Symbol = "Hello, world".into();
+            let s2: Symbol = "Hello, world".into();
+            let s3: Symbol = "Hello, world!".into();
+            assert_eq!(s1, s2);
+            assert_ne!(s1, s3);
+            assert_ne!(s2, s3);
+            assert_eq!(s3.as_str(), "Hello, world!");
+        }
+
+        #[test]
+        fn test_symbol_valid_across_threads() {
+            let s1 = Symbol::intern("shared-across-threads");
+
+            let handle = std::thread::spawn(|| Symbol::intern("shared-across-threads"));
+            let s2 = handle.join().unwrap();
+
+            assert_eq!(s1, s2);
+            assert_eq!(s2.as_str(), "shared-across-threads");
+        }
+
+        #[test]
+        fn test_rc_symbol_interning_and_cloning() {
+            let s1 = RcSymbol::intern("rc-symbol-test");
+            let s2 = s1.clone();
+            assert_eq!(s1, s2);
+            assert_eq!(s1.as_str(), "rc-symbol-test");
+            assert_eq!(s2.as_str(), "rc-symbol-test");
+        }
+
+        #[test]
+        fn test_rc_symbol_reclaims_after_last_drop() {
+            let s1 = RcSymbol::intern("rc-symbol-reclaim-test");
+            drop(s1);
+
+            // The slot should be free to reuse; re-interning must still
+            // resolve to the right string either way.
+            let s2 = RcSymbol::intern("rc-symbol-reclaim-test");
+            assert_eq!(s2.as_str(), "rc-symbol-reclaim-test");
+        }
+
+        #[test]
+        fn test_rc_symbol_valid_across_threads() {
+            let s1 = RcSymbol::intern("rc-symbol-shared-across-threads");
+
+            let handle = std::thread::spawn(|| {
+                let s2 = RcSymbol::intern("rc-symbol-shared-across-threads");
+                assert_eq!(s2.as_str(), "rc-symbol-shared-across-threads");
+                s2
+            });
+            let s2 = handle.join().unwrap();
+
+            assert_eq!(s1, s2);
+            assert_eq!(s1.as_str(), "rc-symbol-shared-across-threads");
+            drop(s2);
+            assert_eq!(s1.as_str(), "rc-symbol-shared-across-threads");
+        }
     }
 
     #[test]
-    fn test_simple_interning() {
-        let s = Symbol::intern("Hello");
-        assert_eq!(s.as_str(), "Hello");
+    fn test_string_interner_is_independent_from_global() {
+        let mut interner = StringInterner::new();
+        let local = interner.get_or_intern("Hello, world");
+        assert_eq!(interner.resolve(local), Some("Hello, world"));
+        assert_eq!(interner.len(), 1);
+
+        let other = StringInterner::new();
+        assert_eq!(other.resolve(local), None);
     }
 
     #[test]
-    fn test_interning_same_string_multiple_times() {
-        let s1 = Symbol::intern("Hello, world");
-        let s2 = Symbol::intern("Hello, world");
-        assert_eq!(s1.as_str(), "Hello, world");
+    fn test_string_interner_deduplicates() {
+        let mut interner = StringInterner::new();
+        let s1 = interner.get_or_intern("dup");
+        let s2 = interner.get_or_intern("dup");
         assert_eq!(s1, s2);
-        assert_eq!(s2.as_str(), "Hello, world");
+        assert_eq!(interner.len(), 1);
     }
 
     #[test]
-    fn test_interning_different_strings() {
-        let s1: Symbol = "Hello, world".into();
-        let s2: Symbol = "Hello, world".into();
-        let s3: Symbol = "Hello, world!".into();
-        assert_eq!(s1, s2);
-        assert_ne!(s1, s3);
-        assert_ne!(s2, s3);
-        assert_eq!(s3.as_str(), "Hello, world!");
+    fn test_string_interner_frees_arena_chunks_on_drop() {
+        // Enough distinct strings to span more than one arena chunk; the
+        // interner's `Drop` should deallocate every one without leaking
+        // or double-freeing.
+        let mut interner = StringInterner::new();
+        for i in 0..2000 {
+            interner.get_or_intern(&i.to_string());
+        }
+        drop(interner);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_string_interner_roundtrips_through_serde() {
+        let mut interner = StringInterner::new();
+        let a = interner.get_or_intern("alpha");
+        let b = interner.get_or_intern("beta");
+
+        let json = serde_json::to_string(&interner).unwrap();
+        let restored: StringInterner = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.resolve(a), Some("alpha"));
+        assert_eq!(restored.resolve(b), Some("beta"));
+        assert_eq!(restored.len(), interner.len());
     }
 }